@@ -0,0 +1,54 @@
+/// Linearly resamples `samples` from `from_rate` Hz to `to_rate` Hz, interpolating
+/// between neighboring input samples rather than just picking the nearest one. A no-op
+/// when the rates already match. This doesn't anti-alias, so heavy downsampling (e.g.
+/// 48 kHz -> 16 kHz) can still introduce some aliasing.
+pub fn resample_linear(samples: &[f32], from_rate: u32, to_rate: u32) -> Vec<f32> {
+    if from_rate == to_rate || samples.is_empty() {
+        return samples.to_vec();
+    }
+
+    let ratio = from_rate as f64 / to_rate as f64;
+    let out_len = (samples.len() as f64 / ratio).round() as usize;
+    (0..out_len)
+        .map(|i| {
+            let src_pos = i as f64 * ratio;
+            let src_index = src_pos.floor() as usize;
+            let frac = (src_pos - src_index as f64) as f32;
+            let a = samples.get(src_index).copied().unwrap_or(0.0);
+            let b = samples.get(src_index + 1).copied().unwrap_or(a);
+            a + (b - a) * frac
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_rate_is_a_no_op() {
+        let samples = vec![0.1, 0.2, 0.3, 0.4];
+        assert_eq!(resample_linear(&samples, 16_000, 16_000), samples);
+    }
+
+    #[test]
+    fn downsamples_to_the_expected_length() {
+        let samples = vec![0.0; 48_000];
+        let resampled = resample_linear(&samples, 48_000, 16_000);
+        assert_eq!(resampled.len(), 16_000);
+    }
+
+    #[test]
+    fn empty_input_stays_empty() {
+        assert!(resample_linear(&[], 48_000, 16_000).is_empty());
+    }
+
+    #[test]
+    fn interpolates_between_neighboring_samples() {
+        let samples = vec![0.0, 2.0, 4.0, 6.0];
+        let resampled = resample_linear(&samples, 3, 2);
+        // Nearest-neighbor would pick exactly [0.0, 2.0, 6.0]; true interpolation
+        // should land on the midpoint between samples[1] and samples[2] for index 1.
+        assert_eq!(resampled, vec![0.0, 3.0, 6.0]);
+    }
+}
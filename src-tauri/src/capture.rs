@@ -0,0 +1,242 @@
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex as StdMutex};
+
+use cpal::traits::{DeviceTrait, HostTrait, StreamTrait};
+use cpal::{SampleFormat, Stream};
+use serde::Serialize;
+use tauri::Manager;
+
+use crate::audio::resample_linear;
+
+const SAMPLE_RATE: u32 = 16_000;
+const RING_BUFFER_CAPACITY: usize = SAMPLE_RATE as usize * 60; // 60s of mono i16 samples
+
+struct RingBuffer {
+    samples: VecDeque<i16>,
+}
+
+impl RingBuffer {
+    fn new() -> Self {
+        Self {
+            samples: VecDeque::with_capacity(RING_BUFFER_CAPACITY),
+        }
+    }
+
+    fn push(&mut self, frame: &[i16]) {
+        for &sample in frame {
+            if self.samples.len() == RING_BUFFER_CAPACITY {
+                self.samples.pop_front();
+            }
+            self.samples.push_back(sample);
+        }
+    }
+}
+
+/// Holds the open capture stream and accumulated samples while recording.
+#[derive(Default)]
+pub struct CaptureState {
+    inner: StdMutex<Option<CaptureSession>>,
+}
+
+struct CaptureSession {
+    stream: Stream,
+    buffer: Arc<StdMutex<RingBuffer>>,
+}
+
+// cpal::Stream is not Send on some platforms; it only ever lives behind this
+// Tauri-managed mutex on the main thread that owns it.
+unsafe impl Send for CaptureSession {}
+
+#[derive(Clone, Serialize)]
+struct AudioLevel {
+    rms: f32,
+}
+
+/// Opens the default input device and starts accumulating samples into a bounded ring
+/// buffer, emitting `audio_level` events for a live VU meter as frames arrive.
+#[tauri::command]
+pub fn start_recording(
+    app_handle: tauri::AppHandle,
+    state: tauri::State<'_, CaptureState>,
+) -> Result<(), String> {
+    let host = cpal::default_host();
+    let device = host
+        .default_input_device()
+        .ok_or_else(|| "No input device available".to_string())?;
+    let config = device
+        .default_input_config()
+        .map_err(|e| format!("Failed to get input config: {}", e))?;
+
+    let buffer = Arc::new(StdMutex::new(RingBuffer::new()));
+    let buffer_for_callback = buffer.clone();
+    let emit_handle = app_handle.clone();
+    let channels = config.channels() as usize;
+    let source_rate = config.sample_rate().0;
+    let sample_format = config.sample_format();
+    let stream_config = config.into();
+
+    let err_fn = |err| eprintln!("Audio capture stream error: {}", err);
+
+    let stream = match sample_format {
+        SampleFormat::F32 => device
+            .build_input_stream(
+                &stream_config,
+                move |data: &[f32], _| {
+                    handle_input_frame(
+                        data,
+                        channels,
+                        source_rate,
+                        &buffer_for_callback,
+                        &emit_handle,
+                    )
+                },
+                err_fn,
+                None,
+            )
+            .map_err(|e| format!("Failed to open input stream: {}", e))?,
+        // WASAPI and ALSA commonly default to I16/U16 devices, so these need to be
+        // supported rather than erroring out on the most common hardware.
+        SampleFormat::I16 => device
+            .build_input_stream(
+                &stream_config,
+                move |data: &[i16], _| {
+                    let floats: Vec<f32> =
+                        data.iter().map(|&s| s as f32 / i16::MAX as f32).collect();
+                    handle_input_frame(
+                        &floats,
+                        channels,
+                        source_rate,
+                        &buffer_for_callback,
+                        &emit_handle,
+                    )
+                },
+                err_fn,
+                None,
+            )
+            .map_err(|e| format!("Failed to open input stream: {}", e))?,
+        SampleFormat::U16 => device
+            .build_input_stream(
+                &stream_config,
+                move |data: &[u16], _| {
+                    let floats: Vec<f32> = data
+                        .iter()
+                        .map(|&s| (s as f32 - u16::MAX as f32 / 2.0) / (u16::MAX as f32 / 2.0))
+                        .collect();
+                    handle_input_frame(
+                        &floats,
+                        channels,
+                        source_rate,
+                        &buffer_for_callback,
+                        &emit_handle,
+                    )
+                },
+                err_fn,
+                None,
+            )
+            .map_err(|e| format!("Failed to open input stream: {}", e))?,
+        other => return Err(format!("Unsupported sample format: {:?}", other)),
+    };
+
+    stream
+        .play()
+        .map_err(|e| format!("Failed to start input stream: {}", e))?;
+
+    *state
+        .inner
+        .lock()
+        .map_err(|_| "Capture state poisoned".to_string())? = Some(CaptureSession { stream, buffer });
+
+    Ok(())
+}
+
+fn handle_input_frame(
+    data: &[f32],
+    channels: usize,
+    source_rate: u32,
+    buffer: &Arc<StdMutex<RingBuffer>>,
+    app_handle: &tauri::AppHandle,
+) {
+    let mono: Vec<f32> = data
+        .chunks(channels.max(1))
+        .map(|frame| frame.iter().sum::<f32>() / channels.max(1) as f32)
+        .collect();
+
+    let rms = (mono.iter().map(|s| s.powi(2)).sum::<f32>() / mono.len().max(1) as f32).sqrt();
+    let _ = app_handle.emit_all("audio_level", AudioLevel { rms });
+
+    // The ring buffer (and the WAV header written in `encode_wav`) always assumes
+    // `SAMPLE_RATE`, so resample from whatever rate the device actually opened at.
+    let resampled = resample_linear(&mono, source_rate, SAMPLE_RATE);
+    let pcm: Vec<i16> = resampled
+        .iter()
+        .map(|sample| (sample.clamp(-1.0, 1.0) * i16::MAX as f32) as i16)
+        .collect();
+
+    if let Ok(mut buffer) = buffer.lock() {
+        buffer.push(&pcm);
+    }
+}
+
+/// Stops the capture stream and encodes the buffered PCM to a 16-bit mono 16 kHz WAV,
+/// ready to feed into the Deepgram HTTP or streaming path.
+#[tauri::command]
+pub fn stop_recording(state: tauri::State<'_, CaptureState>) -> Result<Vec<u8>, String> {
+    let session = state
+        .inner
+        .lock()
+        .map_err(|_| "Capture state poisoned".to_string())?
+        .take()
+        .ok_or_else(|| "No active recording".to_string())?;
+
+    drop(session.stream);
+
+    let samples = session
+        .buffer
+        .lock()
+        .map_err(|_| "Capture buffer poisoned".to_string())?
+        .samples
+        .iter()
+        .copied()
+        .collect::<Vec<i16>>();
+
+    encode_wav(&samples)
+}
+
+fn encode_wav(samples: &[i16]) -> Result<Vec<u8>, String> {
+    let mut wav = Vec::new();
+    {
+        let spec = hound::WavSpec {
+            channels: 1,
+            sample_rate: SAMPLE_RATE,
+            bits_per_sample: 16,
+            sample_format: hound::SampleFormat::Int,
+        };
+        let mut writer = hound::WavWriter::new(std::io::Cursor::new(&mut wav), spec)
+            .map_err(|e| format!("Failed to create WAV writer: {}", e))?;
+        for &sample in samples {
+            writer
+                .write_sample(sample)
+                .map_err(|e| format!("Failed to write sample: {}", e))?;
+        }
+        writer
+            .finalize()
+            .map_err(|e| format!("Failed to finalize WAV: {}", e))?;
+    }
+    Ok(wav)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encoded_wav_declares_the_real_sample_rate() {
+        let wav = encode_wav(&[0, 100, -100, 200]).expect("encode_wav should succeed");
+        let reader =
+            hound::WavReader::new(std::io::Cursor::new(wav)).expect("wav should be readable");
+        let spec = reader.spec();
+        assert_eq!(spec.sample_rate, SAMPLE_RATE);
+        assert_eq!(spec.channels, 1);
+        assert_eq!(spec.bits_per_sample, 16);
+    }
+}
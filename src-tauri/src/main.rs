@@ -6,6 +6,19 @@ use enigo::{Enigo, KeyboardControllable};
 use reqwest::multipart;
 use serde_json::Value;
 
+mod audio;
+mod capture;
+mod options;
+mod postprocess;
+mod streaming;
+mod usage;
+mod whisper_local;
+
+use capture::CaptureState;
+use options::{TranscriptionOptions, TranscriptionResult};
+use streaming::StreamingState;
+use whisper_local::WhisperState;
+
 #[tauri::command]
 async fn copy_to_clipboard(app_handle: tauri::AppHandle, text: String) -> Result<(), String> {
     app_handle
@@ -55,40 +68,114 @@ async fn test_deepgram_connection(api_key: String) -> Result<(), String> {
 }
 
 #[tauri::command]
-async fn transcribe_audio(api_key: String, audio_data: Vec<u8>) -> Result<String, String> {
+async fn transcribe_audio(
+    api_key: String,
+    audio_data: Vec<u8>,
+    options: Option<TranscriptionOptions>,
+) -> Result<TranscriptionResult, String> {
+    let options = options.unwrap_or_default();
     let client = reqwest::Client::new();
-    
+
     let form = multipart::Form::new()
         .part("audio", multipart::Part::bytes(audio_data)
             .file_name("audio.webm")
             .mime_str("audio/webm").unwrap());
-    
+
     let response = client
-        .post("https://api.deepgram.com/v1/listen?model=nova-2&smart_format=true")
+        .post("https://api.deepgram.com/v1/listen")
+        .query(&options.to_query_params())
         .header("Authorization", format!("Token {}", api_key))
         .multipart(form)
         .send()
         .await
         .map_err(|e| format!("Request failed: {}", e))?;
-    
+
     if !response.status().is_success() {
         return Err(format!("API error: {}", response.status()));
     }
-    
+
     let json: Value = response.json().await
         .map_err(|e| format!("JSON parse error: {}", e))?;
-    
+
+    if options.diarize {
+        return Ok(TranscriptionResult::Diarized(options::DiarizedTranscript::from_json(&json)));
+    }
+
     let transcript = json["results"]["channels"][0]["alternatives"][0]["transcript"]
         .as_str()
         .unwrap_or("")
         .to_string();
-    
-    Ok(transcript)
+
+    Ok(TranscriptionResult::Flat(transcript))
+}
+
+/// Unified entry point that picks between the Deepgram and local Whisper backends,
+/// falling back to local automatically when a Deepgram request fails and the Deepgram
+/// connection test fails as well.
+#[tauri::command]
+async fn transcribe(
+    app_handle: tauri::AppHandle,
+    whisper_state: tauri::State<'_, WhisperState>,
+    backend: String,
+    api_key: Option<String>,
+    audio_data: Vec<u8>,
+    options: Option<TranscriptionOptions>,
+) -> Result<TranscriptionResult, String> {
+    match backend.as_str() {
+        "local" => {
+            let transcript =
+                whisper_local::transcribe_local(&app_handle, &whisper_state, audio_data).await?;
+            Ok(TranscriptionResult::Flat(transcript))
+        }
+        "deepgram" => {
+            let api_key = api_key.unwrap_or_default();
+            match transcribe_audio(api_key.clone(), audio_data.clone(), options).await {
+                Ok(result) => Ok(result),
+                Err(deepgram_err) => {
+                    // Only fall back when Deepgram itself is unreachable/misconfigured;
+                    // a reachable-but-rejected request (bad audio, rate limit, ...) should
+                    // surface its own error rather than a confusing local-backend one.
+                    if test_deepgram_connection(api_key).await.is_ok() {
+                        return Err(deepgram_err);
+                    }
+
+                    whisper_local::transcribe_local(&app_handle, &whisper_state, audio_data)
+                        .await
+                        .map(TranscriptionResult::Flat)
+                        .map_err(|local_err| {
+                            format!(
+                                "Deepgram failed ({}), and local fallback also failed ({})",
+                                deepgram_err, local_err
+                            )
+                        })
+                }
+            }
+        }
+        other => Err(format!("Unknown transcription backend: {}", other)),
+    }
 }
 
 fn main() {
     tauri::Builder::default()
-        .invoke_handler(tauri::generate_handler![copy_to_clipboard, insert_text, test_deepgram_connection, transcribe_audio])
+        .manage(StreamingState::default())
+        .manage(WhisperState::default())
+        .manage(CaptureState::default())
+        .invoke_handler(tauri::generate_handler![
+            copy_to_clipboard,
+            insert_text,
+            test_deepgram_connection,
+            transcribe_audio,
+            transcribe,
+            streaming::start_streaming_transcription,
+            streaming::send_audio_frame,
+            streaming::stop_streaming_transcription,
+            postprocess::postprocess_transcript,
+            usage::list_projects,
+            usage::get_usage,
+            usage::get_balances,
+            capture::start_recording,
+            capture::stop_recording
+        ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
 }
\ No newline at end of file
@@ -0,0 +1,176 @@
+use serde::{Deserialize, Serialize};
+
+/// Result of a transcription request, which is structured when diarization is requested
+/// and a flat string otherwise.
+#[derive(Debug, Serialize)]
+#[serde(untagged)]
+pub enum TranscriptionResult {
+    Flat(String),
+    Diarized(DiarizedTranscript),
+}
+
+/// User-configurable Deepgram `/v1/listen` request options, deserialized from the
+/// frontend's transcription settings.
+#[derive(Debug, Deserialize)]
+#[serde(default)]
+pub struct TranscriptionOptions {
+    pub model: String,
+    pub language: Option<String>,
+    pub detect_language: bool,
+    pub diarize: bool,
+    pub smart_format: bool,
+    pub punctuate: bool,
+    pub numerals: bool,
+    pub profanity_filter: bool,
+    pub redact: bool,
+    pub keywords: Vec<String>,
+}
+
+impl Default for TranscriptionOptions {
+    fn default() -> Self {
+        Self {
+            model: "nova-2".to_string(),
+            language: None,
+            detect_language: false,
+            diarize: false,
+            smart_format: true,
+            punctuate: true,
+            numerals: false,
+            profanity_filter: false,
+            redact: false,
+            keywords: Vec::new(),
+        }
+    }
+}
+
+impl TranscriptionOptions {
+    /// Serializes the options into Deepgram's `/v1/listen` query parameters.
+    pub fn to_query_params(&self) -> Vec<(String, String)> {
+        let mut params = vec![
+            ("model".to_string(), self.model.clone()),
+            ("smart_format".to_string(), self.smart_format.to_string()),
+            ("punctuate".to_string(), self.punctuate.to_string()),
+            ("numerals".to_string(), self.numerals.to_string()),
+            (
+                "profanity_filter".to_string(),
+                self.profanity_filter.to_string(),
+            ),
+            ("diarize".to_string(), self.diarize.to_string()),
+            ("redact".to_string(), self.redact.to_string()),
+        ];
+
+        if self.detect_language {
+            params.push(("detect_language".to_string(), "true".to_string()));
+        } else if let Some(language) = &self.language {
+            params.push(("language".to_string(), language.clone()));
+        }
+
+        for keyword in &self.keywords {
+            params.push(("keywords".to_string(), keyword.clone()));
+        }
+
+        params
+    }
+}
+
+/// A single transcribed word, optionally tagged with its speaker when diarization is on.
+#[derive(Debug, Serialize)]
+pub struct TranscriptWord {
+    pub word: String,
+    pub start: f64,
+    pub end: f64,
+    pub speaker: Option<i64>,
+}
+
+/// Structured result returned when `diarize` is enabled, carrying per-word speaker labels
+/// alongside the flat transcript.
+#[derive(Debug, Serialize)]
+pub struct DiarizedTranscript {
+    pub transcript: String,
+    pub words: Vec<TranscriptWord>,
+}
+
+impl DiarizedTranscript {
+    pub fn from_json(json: &serde_json::Value) -> Self {
+        let alternative = &json["results"]["channels"][0]["alternatives"][0];
+        let transcript = alternative["transcript"].as_str().unwrap_or("").to_string();
+
+        let words = alternative["words"]
+            .as_array()
+            .map(|words| {
+                words
+                    .iter()
+                    .map(|word| TranscriptWord {
+                        word: word["word"].as_str().unwrap_or("").to_string(),
+                        start: word["start"].as_f64().unwrap_or(0.0),
+                        end: word["end"].as_f64().unwrap_or(0.0),
+                        speaker: word["speaker"].as_i64(),
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Self { transcript, words }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn find<'a>(params: &'a [(String, String)], key: &str) -> Option<&'a str> {
+        params
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.as_str())
+    }
+
+    #[test]
+    fn detect_language_takes_precedence_over_language() {
+        let mut options = TranscriptionOptions::default();
+        options.detect_language = true;
+        options.language = Some("es".to_string());
+
+        let params = options.to_query_params();
+
+        assert_eq!(find(&params, "detect_language"), Some("true"));
+        assert_eq!(find(&params, "language"), None);
+    }
+
+    #[test]
+    fn explicit_language_is_sent_when_not_detecting() {
+        let mut options = TranscriptionOptions::default();
+        options.language = Some("fr".to_string());
+
+        let params = options.to_query_params();
+
+        assert_eq!(find(&params, "language"), Some("fr"));
+        assert_eq!(find(&params, "detect_language"), None);
+    }
+
+    #[test]
+    fn smart_format_defaults_on_but_is_configurable() {
+        let params = TranscriptionOptions::default().to_query_params();
+        assert_eq!(find(&params, "smart_format"), Some("true"));
+
+        let mut options = TranscriptionOptions::default();
+        options.smart_format = false;
+        let params = options.to_query_params();
+        assert_eq!(find(&params, "smart_format"), Some("false"));
+    }
+
+    #[test]
+    fn keywords_are_repeated_as_separate_params() {
+        let mut options = TranscriptionOptions::default();
+        options.keywords = vec!["rust".to_string(), "tauri".to_string()];
+
+        let params = options.to_query_params();
+        let keyword_values: Vec<&str> = params
+            .iter()
+            .filter(|(k, _)| k == "keywords")
+            .map(|(_, v)| v.as_str())
+            .collect();
+
+        assert_eq!(keyword_values, vec!["rust", "tauri"]);
+    }
+}
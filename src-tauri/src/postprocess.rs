@@ -0,0 +1,100 @@
+use eventsource_stream::Eventsource;
+use futures_util::StreamExt;
+use serde::Serialize;
+use serde_json::{json, Value};
+use tauri::Manager;
+
+#[derive(Clone, Serialize)]
+struct PostprocessDelta {
+    delta: String,
+    done: bool,
+}
+
+fn prompt_for_mode(mode: &str, transcript: &str) -> String {
+    if let Some(language) = mode.strip_prefix("translate:") {
+        return format!(
+            "Translate the following transcript into {}. Return only the translation:\n\n{}",
+            language, transcript
+        );
+    }
+
+    match mode {
+        "clean" => format!(
+            "Clean up the punctuation, casing, and filler words in this transcript without changing its meaning. Return only the cleaned text:\n\n{}",
+            transcript
+        ),
+        "summarize" => format!(
+            "Summarize the following transcript concisely:\n\n{}",
+            transcript
+        ),
+        custom => format!("{}\n\n{}", custom, transcript),
+    }
+}
+
+/// Streams an LLM rewrite of a transcript (cleanup, summary, translation, or a custom
+/// prompt) from an OpenAI-compatible chat endpoint, emitting token deltas as they arrive
+/// so the UI can update progressively.
+#[tauri::command]
+pub async fn postprocess_transcript(
+    app_handle: tauri::AppHandle,
+    transcript: String,
+    mode: String,
+    base_url: String,
+    api_key: String,
+    model: String,
+) -> Result<(), String> {
+    let client = reqwest::Client::new();
+    let prompt = prompt_for_mode(&mode, &transcript);
+
+    let response = client
+        .post(format!("{}/chat/completions", base_url.trim_end_matches('/')))
+        .header("Authorization", format!("Bearer {}", api_key))
+        .json(&json!({
+            "model": model,
+            "messages": [{"role": "user", "content": prompt}],
+            "stream": true,
+        }))
+        .send()
+        .await
+        .map_err(|e| format!("Request failed: {}", e))?;
+
+    if !response.status().is_success() {
+        return Err(format!("API error: {}", response.status()));
+    }
+
+    let mut stream = response.bytes_stream().eventsource();
+    while let Some(event) = stream.next().await {
+        let event = event.map_err(|e| format!("Stream error: {}", e))?;
+        if event.data == "[DONE]" {
+            break;
+        }
+
+        let json: Value = serde_json::from_str(&event.data)
+            .map_err(|e| format!("JSON parse error: {}", e))?;
+        let delta = json["choices"][0]["delta"]["content"]
+            .as_str()
+            .unwrap_or("")
+            .to_string();
+        if delta.is_empty() {
+            continue;
+        }
+
+        let _ = app_handle.emit_all(
+            "postprocess_delta",
+            PostprocessDelta {
+                delta,
+                done: false,
+            },
+        );
+    }
+
+    let _ = app_handle.emit_all(
+        "postprocess_delta",
+        PostprocessDelta {
+            delta: String::new(),
+            done: true,
+        },
+    );
+
+    Ok(())
+}
@@ -0,0 +1,108 @@
+use futures_util::{SinkExt, StreamExt};
+use serde::Serialize;
+use serde_json::Value;
+use tauri::Manager;
+use tokio::sync::{mpsc, Mutex};
+use tokio_tungstenite::connect_async;
+use tokio_tungstenite::tungstenite::client::IntoClientRequest;
+use tokio_tungstenite::tungstenite::Message;
+
+const STREAMING_URL: &str =
+    "wss://api.deepgram.com/v1/listen?model=nova-2&interim_results=true&encoding=linear16&sample_rate=16000";
+
+/// Holds the sender half of the channel feeding the active Deepgram socket, if any.
+#[derive(Default)]
+pub struct StreamingState(pub Mutex<Option<mpsc::UnboundedSender<Message>>>);
+
+#[derive(Clone, Serialize)]
+struct TranscriptUpdate {
+    transcript: String,
+    is_final: bool,
+    speech_final: bool,
+}
+
+#[tauri::command]
+pub async fn start_streaming_transcription(
+    app_handle: tauri::AppHandle,
+    state: tauri::State<'_, StreamingState>,
+    api_key: String,
+) -> Result<(), String> {
+    let mut request = STREAMING_URL
+        .into_client_request()
+        .map_err(|e| e.to_string())?;
+    request.headers_mut().insert(
+        "Authorization",
+        format!("Token {}", api_key)
+            .parse()
+            .map_err(|_| "Invalid API key".to_string())?,
+    );
+
+    let (ws_stream, _) = connect_async(request)
+        .await
+        .map_err(|e| format!("Failed to connect to Deepgram: {}", e))?;
+    let (mut write, mut read) = ws_stream.split();
+    let (tx, mut rx) = mpsc::unbounded_channel::<Message>();
+
+    tokio::spawn(async move {
+        while let Some(message) = rx.recv().await {
+            if write.send(message).await.is_err() {
+                break;
+            }
+        }
+    });
+
+    let emit_handle = app_handle.clone();
+    tokio::spawn(async move {
+        while let Some(Ok(message)) = read.next().await {
+            let Message::Text(text) = message else {
+                continue;
+            };
+            let Ok(json) = serde_json::from_str::<Value>(&text) else {
+                continue;
+            };
+            let transcript = json["channel"]["alternatives"][0]["transcript"]
+                .as_str()
+                .unwrap_or("")
+                .to_string();
+            if transcript.is_empty() {
+                continue;
+            }
+            let update = TranscriptUpdate {
+                transcript,
+                is_final: json["is_final"].as_bool().unwrap_or(false),
+                speech_final: json["speech_final"].as_bool().unwrap_or(false),
+            };
+            let _ = emit_handle.emit_all("transcript_update", update);
+        }
+    });
+
+    *state.0.lock().await = Some(tx);
+    Ok(())
+}
+
+/// Forwards a chunk of linear16 PCM audio from the frontend to the open Deepgram socket.
+#[tauri::command]
+pub async fn send_audio_frame(
+    state: tauri::State<'_, StreamingState>,
+    audio_data: Vec<u8>,
+) -> Result<(), String> {
+    let guard = state.0.lock().await;
+    match guard.as_ref() {
+        Some(tx) => tx
+            .send(Message::Binary(audio_data))
+            .map_err(|e| format!("Failed to send audio frame: {}", e)),
+        None => Err("No active streaming session".to_string()),
+    }
+}
+
+#[tauri::command]
+pub async fn stop_streaming_transcription(
+    state: tauri::State<'_, StreamingState>,
+) -> Result<(), String> {
+    let mut guard = state.0.lock().await;
+    if let Some(tx) = guard.take() {
+        let _ = tx.send(Message::Text(r#"{"type":"CloseStream"}"#.to_string()));
+        let _ = tx.send(Message::Close(None));
+    }
+    Ok(())
+}
@@ -0,0 +1,130 @@
+use serde::{Deserialize, Serialize};
+
+/// A Deepgram project, as returned by `GET /v1/projects`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Project {
+    pub project_id: String,
+    pub name: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct ProjectsResponse {
+    projects: Vec<Project>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct UsageSummary {
+    pub total_requests: u64,
+    pub total_hours: f64,
+}
+
+#[derive(Debug, Deserialize)]
+struct UsageResponse {
+    results: Vec<UsageResult>,
+}
+
+#[derive(Debug, Deserialize)]
+struct UsageResult {
+    requests: u64,
+    #[serde(default)]
+    total_hours: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Balance {
+    pub balance_id: String,
+    pub amount: f64,
+    pub units: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct BalancesResponse {
+    balances: Vec<Balance>,
+}
+
+#[tauri::command]
+pub async fn list_projects(api_key: String) -> Result<Vec<Project>, String> {
+    let client = reqwest::Client::new();
+
+    let response = client
+        .get("https://api.deepgram.com/v1/projects")
+        .header("Authorization", format!("Token {}", api_key))
+        .send()
+        .await
+        .map_err(|e| format!("Request failed: {}", e))?;
+
+    if !response.status().is_success() {
+        return Err(format!("API error: {}", response.status()));
+    }
+
+    let parsed: ProjectsResponse = response
+        .json()
+        .await
+        .map_err(|e| format!("JSON parse error: {}", e))?;
+
+    Ok(parsed.projects)
+}
+
+#[tauri::command]
+pub async fn get_usage(
+    api_key: String,
+    project_id: String,
+    start: String,
+    end: String,
+) -> Result<UsageSummary, String> {
+    let client = reqwest::Client::new();
+
+    let response = client
+        .get(format!(
+            "https://api.deepgram.com/v1/projects/{}/usage",
+            project_id
+        ))
+        .query(&[("start", start), ("end", end)])
+        .header("Authorization", format!("Token {}", api_key))
+        .send()
+        .await
+        .map_err(|e| format!("Request failed: {}", e))?;
+
+    if !response.status().is_success() {
+        return Err(format!("API error: {}", response.status()));
+    }
+
+    let parsed: UsageResponse = response
+        .json()
+        .await
+        .map_err(|e| format!("JSON parse error: {}", e))?;
+
+    let total_requests = parsed.results.iter().map(|r| r.requests).sum();
+    let total_hours = parsed.results.iter().map(|r| r.total_hours).sum();
+
+    Ok(UsageSummary {
+        total_requests,
+        total_hours,
+    })
+}
+
+#[tauri::command]
+pub async fn get_balances(api_key: String, project_id: String) -> Result<Vec<Balance>, String> {
+    let client = reqwest::Client::new();
+
+    let response = client
+        .get(format!(
+            "https://api.deepgram.com/v1/projects/{}/balances",
+            project_id
+        ))
+        .header("Authorization", format!("Token {}", api_key))
+        .send()
+        .await
+        .map_err(|e| format!("Request failed: {}", e))?;
+
+    if !response.status().is_success() {
+        return Err(format!("API error: {}", response.status()));
+    }
+
+    let parsed: BalancesResponse = response
+        .json()
+        .await
+        .map_err(|e| format!("JSON parse error: {}", e))?;
+
+    Ok(parsed.balances)
+}
@@ -0,0 +1,204 @@
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use tauri::Manager;
+use tokio::sync::Mutex;
+use whisper_rs::{FullParams, SamplingStrategy, WhisperContext, WhisperContextParameters};
+
+use crate::audio::resample_linear;
+
+const MODEL_RESOURCE: &str = "resources/ggml-base.en.bin";
+
+/// Caches the loaded Whisper model so it isn't reloaded on every call.
+#[derive(Default)]
+pub struct WhisperState(pub Mutex<Option<Arc<WhisperContext>>>);
+
+fn resolve_model_path(app_handle: &tauri::AppHandle) -> Result<PathBuf, String> {
+    app_handle
+        .path_resolver()
+        .resolve_resource(MODEL_RESOURCE)
+        .ok_or_else(|| format!("Bundled Whisper model not found: {}", MODEL_RESOURCE))
+}
+
+async fn load_model(
+    app_handle: &tauri::AppHandle,
+    state: &WhisperState,
+) -> Result<Arc<WhisperContext>, String> {
+    let mut guard = state.0.lock().await;
+    if let Some(context) = guard.as_ref() {
+        return Ok(context.clone());
+    }
+
+    let model_path = resolve_model_path(app_handle)?;
+    let context = tokio::task::spawn_blocking(move || {
+        WhisperContext::new_with_params(
+            &model_path.to_string_lossy(),
+            WhisperContextParameters::default(),
+        )
+    })
+    .await
+    .map_err(|e| format!("Failed to load Whisper model: {}", e))?
+    .map_err(|e| format!("Failed to load Whisper model: {}", e))?;
+
+    let context = Arc::new(context);
+    *guard = Some(context.clone());
+    Ok(context)
+}
+
+/// Transcribes a WAV recording fully offline using a bundled quantized Whisper model.
+pub async fn transcribe_local(
+    app_handle: &tauri::AppHandle,
+    state: &WhisperState,
+    audio_data: Vec<u8>,
+) -> Result<String, String> {
+    let context = load_model(app_handle, state).await?;
+
+    tokio::task::spawn_blocking(move || {
+        let samples = decode_to_mono_16k_f32(&audio_data)?;
+
+        let mut params = FullParams::new(SamplingStrategy::Greedy { best_of: 1 });
+        params.set_print_special(false);
+        params.set_print_progress(false);
+        params.set_print_realtime(false);
+        params.set_print_timestamps(false);
+
+        let mut state = context
+            .create_state()
+            .map_err(|e| format!("Failed to create Whisper state: {}", e))?;
+        state
+            .full(params, &samples)
+            .map_err(|e| format!("Whisper inference failed: {}", e))?;
+
+        let num_segments = state
+            .full_n_segments()
+            .map_err(|e| format!("Failed to read segments: {}", e))?;
+        let mut transcript = String::new();
+        for i in 0..num_segments {
+            if let Ok(segment) = state.full_get_segment_text(i) {
+                transcript.push_str(&segment);
+            }
+        }
+
+        Ok(transcript.trim().to_string())
+    })
+    .await
+    .map_err(|e| format!("Whisper task panicked: {}", e))?
+}
+
+/// Decodes a recording down to 16 kHz mono f32 PCM, the sample format `whisper-rs`
+/// expects. Uses `symphonia`'s default probe and codec registry, so the native capture
+/// path's WAV output decodes here; audio whose container or codec that registry lacks
+/// (such as the Opus audio in the frontend's WebM `MediaRecorder` blobs) is rejected
+/// with an error.
+fn decode_to_mono_16k_f32(audio_data: &[u8]) -> Result<Vec<f32>, String> {
+    use symphonia::core::audio::SampleBuffer;
+    use symphonia::core::codecs::DecoderOptions;
+    use symphonia::core::errors::Error as SymphoniaError;
+    use symphonia::core::formats::FormatOptions;
+    use symphonia::core::io::MediaSourceStream;
+    use symphonia::core::meta::MetadataOptions;
+    use symphonia::core::probe::Hint;
+
+    let source = MediaSourceStream::new(
+        Box::new(std::io::Cursor::new(audio_data.to_vec())),
+        Default::default(),
+    );
+
+    let probed = symphonia::default::get_probe()
+        .format(
+            &Hint::new(),
+            source,
+            &FormatOptions::default(),
+            &MetadataOptions::default(),
+        )
+        .map_err(|e| format!("Unrecognized audio container: {}", e))?;
+    let mut format = probed.format;
+
+    let track = format
+        .default_track()
+        .ok_or_else(|| "Audio container has no decodable track".to_string())?;
+    let track_id = track.id;
+    let source_rate = track
+        .codec_params
+        .sample_rate
+        .ok_or_else(|| "Audio track is missing its sample rate".to_string())?;
+    let channels = track
+        .codec_params
+        .channels
+        .map(|c| c.count())
+        .unwrap_or(1)
+        .max(1);
+
+    let mut decoder = symphonia::default::get_codecs()
+        .make(&track.codec_params, &DecoderOptions::default())
+        .map_err(|e| format!("Unsupported audio codec: {}", e))?;
+
+    let mut samples = Vec::new();
+    loop {
+        let packet = match format.next_packet() {
+            Ok(packet) => packet,
+            Err(SymphoniaError::IoError(_)) => break,
+            Err(e) => return Err(format!("Failed to demux audio: {}", e)),
+        };
+        if packet.track_id() != track_id {
+            continue;
+        }
+
+        match decoder.decode(&packet) {
+            Ok(decoded) => {
+                let mut buffer = SampleBuffer::<f32>::new(decoded.capacity() as u64, *decoded.spec());
+                buffer.copy_interleaved_ref(decoded);
+                samples.extend_from_slice(buffer.samples());
+            }
+            Err(SymphoniaError::DecodeError(_)) => continue,
+            Err(e) => return Err(format!("Failed to decode audio: {}", e)),
+        }
+    }
+
+    let mono: Vec<f32> = if channels > 1 {
+        samples
+            .chunks(channels)
+            .map(|frame| frame.iter().sum::<f32>() / channels as f32)
+            .collect()
+    } else {
+        samples
+    };
+
+    Ok(resample_linear(&mono, source_rate, 16_000))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_wav() -> Vec<u8> {
+        let mut wav = Vec::new();
+        let spec = hound::WavSpec {
+            channels: 1,
+            sample_rate: 48_000,
+            bits_per_sample: 16,
+            sample_format: hound::SampleFormat::Int,
+        };
+        let mut writer = hound::WavWriter::new(std::io::Cursor::new(&mut wav), spec).unwrap();
+        for i in 0..4_800 {
+            let sample = ((i as f32 * 0.1).sin() * i16::MAX as f32) as i16;
+            writer.write_sample(sample).unwrap();
+        }
+        writer.finalize().unwrap();
+        wav
+    }
+
+    #[test]
+    fn decodes_and_resamples_a_real_container() {
+        let samples = decode_to_mono_16k_f32(&sample_wav()).expect("decode should succeed");
+        // 4_800 samples at 48 kHz is 100ms, which should resample down to ~1_600 at 16 kHz.
+        assert!(!samples.is_empty());
+        assert!((samples.len() as i64 - 1_600).abs() < 50);
+    }
+
+    #[test]
+    fn rejects_garbage_input() {
+        let err = decode_to_mono_16k_f32(b"not an audio file").unwrap_err();
+        assert!(err.to_lowercase().contains("container") || err.to_lowercase().contains("codec"));
+    }
+}